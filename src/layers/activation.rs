@@ -0,0 +1,161 @@
+use ndarray::{ArrayD, ArrayViewD, Axis};
+use serde::{Deserialize, Serialize};
+
+use super::{Layer, LayerJson};
+
+/// Selectable activation function.
+///
+/// Because this crate targets zero-knowledge proofs, non-polynomial functions
+/// (`Sigmoid`, `Tanh`, the `exp` inside `Softmax`) cannot be evaluated directly
+/// in-circuit; each such variant carries the low-degree polynomial — or, for the
+/// piecewise-linear families, the slope — that is actually used by [`apply`] and
+/// counted by [`num_muls`]. The coefficients are stored most-significant-first so
+/// that [`ActivationKind::eval_poly`] can evaluate them with Horner's rule, whose
+/// multiplication count equals the polynomial degree.
+///
+/// [`apply`]: Activation::apply
+/// [`num_muls`]: Activation::num_muls
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivationKind {
+    Relu,
+    LeakyRelu { alpha: f32 },
+    Sigmoid { coeffs: Vec<f32> },
+    Tanh { coeffs: Vec<f32> },
+    Softmax { exp_coeffs: Vec<f32> },
+}
+
+impl ActivationKind {
+    /// A degree-3 polynomial approximation of `sigmoid` (odd around its `0.5`
+    /// offset). It is only faithful on roughly `[-4, 4]`, so `apply` clamps the
+    /// input to that domain before evaluating it and the result to `[0, 1]`.
+    #[must_use]
+    pub fn sigmoid() -> Self {
+        Self::Sigmoid {
+            coeffs: vec![-0.005_3, 0.0, 0.197_9, 0.5],
+        }
+    }
+
+    /// A degree-3 odd polynomial approximation of `tanh`. It is only faithful
+    /// on roughly `[-3, 3]`, so `apply` clamps the input to that domain before
+    /// evaluating it and the result to `[-1, 1]`.
+    #[must_use]
+    pub fn tanh() -> Self {
+        Self::Tanh {
+            coeffs: vec![-0.047_6, 0.0, 0.627_6, 0.0],
+        }
+    }
+
+    /// A degree-2 polynomial approximation of `exp` used per-element before the
+    /// softmax normalization divide.
+    #[must_use]
+    pub fn softmax() -> Self {
+        Self::Softmax {
+            exp_coeffs: vec![0.5, 1.0, 1.0],
+        }
+    }
+
+    /// Horner evaluation of a coefficient slice, most-significant term first.
+    fn eval_poly(coeffs: &[f32], x: f32) -> f32 {
+        coeffs.iter().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// Number of multiplications spent per input element by this activation's
+    /// approximation. Horner evaluation of a degree-`d` polynomial costs `d`
+    /// muls; `Softmax` adds one reciprocal multiply for the normalization.
+    #[must_use]
+    pub fn muls_per_element(&self) -> usize {
+        match self {
+            Self::Relu => 0,
+            Self::LeakyRelu { .. } => 1,
+            Self::Sigmoid { coeffs } | Self::Tanh { coeffs } => coeffs.len().saturating_sub(1),
+            Self::Softmax { exp_coeffs } => exp_coeffs.len().saturating_sub(1) + 1,
+        }
+    }
+}
+
+/// Elementwise nonlinearity evaluated through a ZK-friendly approximation.
+pub struct Activation {
+    kind: ActivationKind,
+}
+
+impl Activation {
+    #[must_use]
+    pub fn new(kind: ActivationKind) -> Self {
+        Self { kind }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> &ActivationKind {
+        &self.kind
+    }
+}
+
+impl Layer for Activation {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        match &self.kind {
+            ActivationKind::Relu => input.mapv(|x| x.max(0.0)),
+            ActivationKind::LeakyRelu { alpha } => {
+                input.mapv(|x| if x >= 0.0 { x } else { alpha * x })
+            }
+            ActivationKind::Sigmoid { coeffs } => input.mapv(|x| {
+                ActivationKind::eval_poly(coeffs, x.clamp(-4.0, 4.0)).clamp(0.0, 1.0)
+            }),
+            ActivationKind::Tanh { coeffs } => input.mapv(|x| {
+                ActivationKind::eval_poly(coeffs, x.clamp(-3.0, 3.0)).clamp(-1.0, 1.0)
+            }),
+            ActivationKind::Softmax { exp_coeffs } => {
+                let mut exps = input.mapv(|x| ActivationKind::eval_poly(exp_coeffs, x));
+                // Normalize per sample along the last (class) axis, so batched
+                // inputs are not collapsed into one global distribution.
+                let axis = Axis(exps.ndim().saturating_sub(1));
+                for mut lane in exps.lanes_mut(axis) {
+                    let sum: f32 = lane.sum();
+                    lane.mapv_inplace(|e| e / sum);
+                }
+                exps
+            }
+        }
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        // Activations are elementwise and shape-agnostic; the concrete shape is
+        // threaded in by `NeuralNetwork::validate`.
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        match self.kind {
+            ActivationKind::Relu => "Activation<Relu>",
+            ActivationKind::LeakyRelu { .. } => "Activation<LeakyReLU>",
+            ActivationKind::Sigmoid { .. } => "Activation<Sigmoid>",
+            ActivationKind::Tanh { .. } => "Activation<Tanh>",
+            ActivationKind::Softmax { .. } => "Activation<Softmax>",
+        }
+    }
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn num_muls(&self) -> usize {
+        // Per-element cost; the total is `num_muls_with_shape` once the element
+        // count is known.
+        self.kind.muls_per_element()
+    }
+
+    fn num_muls_with_shape(&self, input_shape: &[usize]) -> usize {
+        self.kind.muls_per_element() * input_shape.iter().product::<usize>()
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        // Activations are elementwise and preserve the input shape.
+        vec![]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::Activation {
+            kind: self.kind.clone(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use ndarray::{ArcArray, ArrayD, ArrayViewD, Ix3, IxDyn};
+
+use super::{Layer, LayerJson};
+
+/// Valid (no-padding) convolution of a single `(channels, height, width)`
+/// kernel over a `(channels, height, width)` input, producing a 2-D feature
+/// map.
+pub struct Convolution {
+    pub kernel: ArcArray<f32, Ix3>,
+}
+
+impl Convolution {
+    #[must_use]
+    pub fn new(kernel: ArcArray<f32, Ix3>) -> Self {
+        Self { kernel }
+    }
+}
+
+impl Layer for Convolution {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        let input = input
+            .view()
+            .into_dimensionality::<Ix3>()
+            .expect("convolution expects a 3-D input");
+        let (channels, kh, kw) = self.kernel.dim();
+        let (_, height, width) = input.dim();
+        let (out_h, out_w) = (height - kh + 1, width - kw + 1);
+
+        let mut output = ArrayD::<f32>::zeros(IxDyn(&[out_h, out_w]));
+        for i in 0..out_h {
+            for j in 0..out_w {
+                let mut acc = 0.0;
+                for c in 0..channels {
+                    for di in 0..kh {
+                        for dj in 0..kw {
+                            acc += input[[c, i + di, j + dj]] * self.kernel[[c, di, dj]];
+                        }
+                    }
+                }
+                output[[i, j]] = acc;
+            }
+        }
+        output
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        "Convolution"
+    }
+
+    fn num_params(&self) -> usize {
+        self.kernel.len()
+    }
+
+    fn num_muls(&self) -> usize {
+        0
+    }
+
+    fn num_muls_with_shape(&self, input_shape: &[usize]) -> usize {
+        if input_shape.len() != 3 {
+            return 0;
+        }
+        let (_, kh, kw) = self.kernel.dim();
+        let out_elems = (input_shape[1] - kh + 1) * (input_shape[2] - kw + 1);
+        // One multiply per kernel weight per output element.
+        out_elems * self.kernel.len()
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn output_shape_with_shape(&self, input_shape: &[usize]) -> Vec<usize> {
+        if input_shape.len() != 3 {
+            return input_shape.to_vec();
+        }
+        let (_, kh, kw) = self.kernel.dim();
+        vec![input_shape[1] - kh + 1, input_shape[2] - kw + 1]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::Convolution {
+            kernel: self.kernel.clone(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
+
+use super::{Layer, LayerJson};
+
+/// Collapses a multi-dimensional input into a single 1-D vector, the usual
+/// bridge between the convolutional stack and the dense head.
+pub struct Flatten;
+
+impl Flatten {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Flatten {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layer for Flatten {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        let len = input.len();
+        input
+            .to_shape(IxDyn(&[len]))
+            .expect("contiguous input")
+            .to_owned()
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        "Flatten"
+    }
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn num_muls(&self) -> usize {
+        0
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn output_shape_with_shape(&self, input_shape: &[usize]) -> Vec<usize> {
+        vec![input_shape.iter().product()]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::Flatten
+    }
+}
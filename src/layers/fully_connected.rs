@@ -0,0 +1,55 @@
+use ndarray::{ArcArray, ArrayD, ArrayViewD, Ix1, Ix2};
+
+use super::{Layer, LayerJson};
+
+/// Dense layer computing `weights · x + biases`.
+///
+/// `weights` is `(out_features, in_features)`; `biases` is `(out_features)`.
+pub struct FullyConnected {
+    weights: ArcArray<f32, Ix2>,
+    biases:  ArcArray<f32, Ix1>,
+}
+
+impl FullyConnected {
+    #[must_use]
+    pub fn new(weights: ArcArray<f32, Ix2>, biases: ArcArray<f32, Ix1>) -> Self {
+        Self { weights, biases }
+    }
+}
+
+impl Layer for FullyConnected {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        let flat = input
+            .to_shape(self.weights.ncols())
+            .expect("input length matches weight columns");
+        let out = self.weights.dot(&flat) + self.biases.view();
+        out.into_dyn()
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        vec![self.weights.ncols()]
+    }
+
+    fn name(&self) -> &str {
+        "FullyConnected"
+    }
+
+    fn num_params(&self) -> usize {
+        self.weights.len() + self.biases.len()
+    }
+
+    fn num_muls(&self) -> usize {
+        self.weights.len()
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![self.weights.nrows()]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::FullyConnected {
+            weights: self.weights.clone(),
+            biases:  self.biases.clone(),
+        }
+    }
+}
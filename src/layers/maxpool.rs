@@ -0,0 +1,149 @@
+use ndarray::{Array3, ArrayD, ArrayViewD, IxDyn};
+
+use super::{Layer, LayerJson, PoolingType};
+
+/// Spatial pooling over the last two axes of the input.
+///
+/// Generalizes the original max-only, unit-stride layer: `Max` keeps the
+/// largest element of each `window × window` receptive field, `Average` keeps
+/// its mean (one multiply-by-reciprocal per output element). Successive windows
+/// are spaced `stride` apart, so each pooled axis shrinks to
+/// `(input_dim - window) / stride + 1`.
+pub struct MaxPool {
+    window:       usize,
+    stride:       usize,
+    pooling_type: PoolingType,
+}
+
+impl MaxPool {
+    #[must_use]
+    pub fn new(window: usize, stride: usize, pooling_type: PoolingType) -> Self {
+        Self {
+            window,
+            stride,
+            pooling_type,
+        }
+    }
+
+    /// Pooled length of a single spatial axis.
+    fn pooled_dim(&self, dim: usize) -> usize {
+        (dim - self.window) / self.stride + 1
+    }
+}
+
+impl Layer for MaxPool {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        let shape = input.shape();
+        let ndim = shape.len();
+        assert!(ndim >= 2, "MaxPool expects at least two spatial dimensions");
+
+        let (height, width) = (shape[ndim - 2], shape[ndim - 1]);
+        let (out_h, out_w) = (self.pooled_dim(height), self.pooled_dim(width));
+
+        // Collapse the leading (channel/batch) axes so the pooling loop is a
+        // plain 3-D sweep, then restore the original leading dims afterwards.
+        let lead: usize = shape[..ndim - 2].iter().product();
+        let reshaped = input
+            .to_shape((lead, height, width))
+            .expect("contiguous input");
+
+        let mut output = Array3::<f32>::zeros((lead, out_h, out_w));
+        let recip = 1.0 / (self.window * self.window) as f32;
+
+        for b in 0..lead {
+            for i in 0..out_h {
+                for j in 0..out_w {
+                    let (r0, c0) = (i * self.stride, j * self.stride);
+                    let cell = match self.pooling_type {
+                        PoolingType::Max => {
+                            let mut acc = f32::NEG_INFINITY;
+                            for dr in 0..self.window {
+                                for dc in 0..self.window {
+                                    acc = acc.max(reshaped[[b, r0 + dr, c0 + dc]]);
+                                }
+                            }
+                            acc
+                        }
+                        PoolingType::Average => {
+                            let mut acc = 0.0;
+                            for dr in 0..self.window {
+                                for dc in 0..self.window {
+                                    acc += reshaped[[b, r0 + dr, c0 + dc]];
+                                }
+                            }
+                            acc * recip
+                        }
+                    };
+                    output[[b, i, j]] = cell;
+                }
+            }
+        }
+
+        let mut out_shape = shape.to_vec();
+        out_shape[ndim - 2] = out_h;
+        out_shape[ndim - 1] = out_w;
+        output
+            .into_dyn()
+            .into_shape_with_order(IxDyn(&out_shape))
+            .expect("pooled element count matches")
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        // Depends on the live input; threaded in by `NeuralNetwork::validate`.
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        match self.pooling_type {
+            PoolingType::Max => "MaxPool",
+            PoolingType::Average => "AvgPool",
+        }
+    }
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn num_muls(&self) -> usize {
+        0
+    }
+
+    fn num_muls_with_shape(&self, input_shape: &[usize]) -> usize {
+        let ndim = input_shape.len();
+        if ndim < 2 {
+            return 0;
+        }
+        let out_elems = input_shape[..ndim - 2].iter().product::<usize>()
+            * self.pooled_dim(input_shape[ndim - 2])
+            * self.pooled_dim(input_shape[ndim - 1]);
+        match self.pooling_type {
+            // One reciprocal multiply per output element; max pooling is
+            // comparison-only.
+            PoolingType::Average => out_elems,
+            PoolingType::Max => 0,
+        }
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn output_shape_with_shape(&self, input_shape: &[usize]) -> Vec<usize> {
+        let ndim = input_shape.len();
+        if ndim < 2 {
+            return input_shape.to_vec();
+        }
+        let mut shape = input_shape.to_vec();
+        shape[ndim - 2] = self.pooled_dim(shape[ndim - 2]);
+        shape[ndim - 1] = self.pooled_dim(shape[ndim - 1]);
+        shape
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::MaxPool {
+            window:       self.window,
+            stride:       self.stride,
+            pooling_type: self.pooling_type,
+        }
+    }
+}
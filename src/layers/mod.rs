@@ -1,8 +1,10 @@
 use std::fmt::{Display, Formatter, Result};
+use std::io::{BufRead, Read, Write};
 
 use ndarray::{ArcArray, ArrayD, ArrayViewD, Ix1, Ix2, Ix3};
 use serde::{Deserialize, Serialize};
 
+pub mod activation;
 pub mod conv;
 pub mod flatten;
 pub mod fully_connected;
@@ -10,7 +12,7 @@ pub mod maxpool;
 pub mod normalize;
 pub mod relu;
 
-pub trait Layer: Into<LayerJson> {
+pub trait Layer {
     #[must_use]
     fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32>;
 
@@ -25,7 +27,40 @@ pub trait Layer: Into<LayerJson> {
     #[must_use]
     fn num_muls(&self) -> usize;
 
+    /// Number of multiplications this layer contributes for a concrete input
+    /// shape.
+    ///
+    /// Defaults to [`Layer::num_muls`]. Layers whose multiply count scales with
+    /// the element count — elementwise activations, average pooling — override
+    /// this so the live shape threaded by [`NeuralNetwork::validate`] is
+    /// accounted for instead of a shape-independent constant.
+    fn num_muls_with_shape(&self, _input_shape: &[usize]) -> usize {
+        self.num_muls()
+    }
+
     fn output_shape(&self) -> Vec<usize>;
+
+    /// Output shape produced for a concrete input shape.
+    ///
+    /// Defaults to [`Layer::output_shape`] when that is non-empty, otherwise
+    /// passes the input shape through unchanged. Layers whose output geometry
+    /// depends on the input — pooling (strided reduction), flatten (product),
+    /// convolution (valid correlation) — override this so
+    /// [`NeuralNetwork::validate`] threads the correct shape downstream.
+    fn output_shape_with_shape(&self, input_shape: &[usize]) -> Vec<usize> {
+        let declared = self.output_shape();
+        if declared.is_empty() {
+            input_shape.to_vec()
+        } else {
+            declared
+        }
+    }
+
+    /// Emit this layer's parameters as a [`LayerJson`] for serialization.
+    ///
+    /// Takes `&self` so it can be called through a `Box<dyn Layer>`, which a
+    /// by-value `Into<LayerJson>` conversion cannot.
+    fn to_json(&self) -> LayerJson;
 }
 
 impl Display for Box<dyn Layer> {
@@ -42,61 +77,137 @@ impl Display for Box<dyn Layer> {
     }
 }
 
+/// Pooling strategy used by the [`maxpool::MaxPool`] layer.
+///
+/// `Max` keeps the largest element of each receptive field, `Average` keeps the
+/// arithmetic mean. The name of the layer is kept for backward compatibility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolingType {
+    Max,
+    Average,
+}
+
+impl Default for PoolingType {
+    fn default() -> Self {
+        Self::Max
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Layers {
     Convolution,
     MaxPool,
+    Activation,
     Relu,
     Flatten,
     FullyConnected,
     Normalize,
 }
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+// Externally tagged (the serde default): the only representation that
+// round-trips through non-self-describing / compact backends like bincode and
+// MessagePack, which cannot resolve an internal `"type"` tag.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-#[serde(tag = "type")]
 pub enum LayerJson {
     Convolution {
         kernel: ArcArray<f32, Ix3>,
     },
     MaxPool {
         window: usize,
+        /// Step between successive windows. A value of `0` (the serde default,
+        /// for JSON written before strides existed) is treated as `window`.
+        #[serde(default)]
+        stride: usize,
+        #[serde(default)]
+        pooling_type: PoolingType,
     },
     FullyConnected {
         weights: ArcArray<f32, Ix2>,
         biases:  ArcArray<f32, Ix1>,
     },
+    Activation {
+        kind: activation::ActivationKind,
+    },
     Relu,
     Flatten,
     Normalize,
 }
 
-// Into for each layer
-impl Into<LayerJson> for conv::Convolution {
-    fn into(self) -> LayerJson {
-        LayerJson::Convolution {
-            kernel: self.kernel.clone(),
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct NNJson {
+    pub layers: Vec<LayerJson>,
+}
+
+/// Error raised while reconstructing a layer or a whole network from its
+/// serialized [`LayerJson`] / [`NNJson`] representation.
+///
+/// Carries enough context — the offending layer index, name and field — to tell
+/// the user *which* part of the document was malformed instead of a bare `()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelError {
+    /// A layer's stored parameters were internally inconsistent.
+    InvalidLayer {
+        layer:  &'static str,
+        field:  &'static str,
+        reason: String,
+    },
+    /// Reconstruction of the layer at `index` failed.
+    Layer {
+        index:  usize,
+        source: Box<ModelError>,
+    },
+}
+
+impl Display for ModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::InvalidLayer {
+                layer,
+                field,
+                reason,
+            } => write!(f, "invalid `{field}` in {layer} layer: {reason}"),
+            Self::Layer { index, source } => {
+                write!(f, "failed to build layer {index}: {source}")
+            }
         }
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub struct NNJson {
-    pub layers: Vec<Layers>,
-}
+impl std::error::Error for ModelError {}
 
 impl TryFrom<LayerJson> for Box<dyn Layer> {
-    type Error = ();
+    type Error = ModelError;
 
-    fn try_from(value: LayerJson) -> std::result::Result<Self, ()> {
+    fn try_from(value: LayerJson) -> std::result::Result<Self, ModelError> {
         Ok(match value {
             LayerJson::Convolution { kernel } => Box::new(conv::Convolution::new(kernel)),
-            LayerJson::MaxPool { window } => Box::new(maxpool::MaxPool::new(window)),
+            LayerJson::MaxPool {
+                window,
+                stride,
+                pooling_type,
+            } => {
+                let stride = if stride == 0 { window } else { stride };
+                Box::new(maxpool::MaxPool::new(window, stride, pooling_type))
+            }
             LayerJson::FullyConnected { weights, biases } => {
-                Box::new(fully_connected::FullyConnected::new())
+                if weights.nrows() != biases.len() {
+                    return Err(ModelError::InvalidLayer {
+                        layer:  "fully_connected",
+                        field:  "biases",
+                        reason: format!(
+                            "expected {} biases (one per output row), found {}",
+                            weights.nrows(),
+                            biases.len()
+                        ),
+                    });
+                }
+                Box::new(fully_connected::FullyConnected::new(weights, biases))
             }
+            LayerJson::Activation { kind } => Box::new(activation::Activation::new(kind)),
             LayerJson::Flatten => Box::new(flatten::Flatten::new()),
             LayerJson::Relu => Box::new(relu::Relu::new()),
             LayerJson::Normalize => Box::new(normalize::Normalize::new()),
@@ -107,22 +218,27 @@ impl TryFrom<LayerJson> for Box<dyn Layer> {
 impl From<NeuralNetwork> for NNJson {
     fn from(nn: NeuralNetwork) -> Self {
         Self {
-            layers: nn.layers.into_iter().map(|l| l.into()).collect(),
+            layers: nn.layers.iter().map(|l| l.to_json()).collect(),
         }
     }
 }
 
 impl TryFrom<NNJson> for NeuralNetwork {
-    type Error = ();
-
-    fn try_from(value: NNJson) -> std::result::Result<Self, ()> {
-        Ok(Self {
-            layers: value
-                .layers
-                .into_iter()
-                .map(|l| l.try_into())
-                .collect::<Result<Vec<_>, _>>()?,
-        })
+    type Error = ModelError;
+
+    fn try_from(value: NNJson) -> std::result::Result<Self, ModelError> {
+        let layers = value
+            .layers
+            .into_iter()
+            .enumerate()
+            .map(|(index, l)| {
+                Box::<dyn Layer>::try_from(l).map_err(|source| ModelError::Layer {
+                    index,
+                    source: Box::new(source),
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(Self { layers })
     }
 }
 
@@ -149,24 +265,294 @@ impl NeuralNetwork {
         self.layers.push(layer);
     }
 
-    pub fn apply(&self, input: &ArrayViewD<f32>, dim: usize) -> Option<ArrayD<f32>> {
-        if dim == 3 {
-            let mut output = input.view().into_owned();
+    /// Walk the layer list once, threading each layer's `output_shape` into the
+    /// next layer's `input_shape`, and return an aggregate [`NetworkReport`].
+    ///
+    /// A layer that declares a fixed `input_shape` (e.g. a `FullyConnected`
+    /// whose weight-matrix columns fix the flattened length) must match the shape
+    /// flowing into it; otherwise a [`ShapeError`] naming the offending layer
+    /// index and the expected-vs-actual shapes is returned. Shape-agnostic
+    /// elementwise layers report an empty shape and pass the current one
+    /// through unchanged.
+    pub fn validate(&self, input_shape: &[usize]) -> std::result::Result<NetworkReport, ShapeError> {
+        let mut shape = input_shape.to_vec();
+        let mut num_params = 0;
+        let mut num_muls = 0;
 
-            for layer in &self.layers {
-                // TODO: add dimensionality sanity checks
-                output = layer.apply(&output.view());
-                println!("{}", layer);
+        for (index, layer) in self.layers.iter().enumerate() {
+            let expected = layer.input_shape();
+            if !expected.is_empty() && expected != shape {
+                return Err(ShapeError {
+                    layer_index: index,
+                    layer_name:  layer.name().to_string(),
+                    expected,
+                    actual: shape,
+                });
             }
-            Some(output)
-        } else {
-            None
+
+            num_params += layer.num_params();
+            num_muls += layer.num_muls_with_shape(&shape);
+
+            shape = layer.output_shape_with_shape(&shape);
+        }
+
+        Ok(NetworkReport {
+            num_layers: self.layers.len(),
+            num_params,
+            num_muls,
+            output_shape: shape,
+        })
+    }
+
+    /// Build a network from newline-delimited JSON, one [`LayerJson`] object
+    /// per line, appending each through the existing [`TryFrom`] path.
+    ///
+    /// Lets very large models be assembled or streamed layer-by-layer without
+    /// materializing the whole document as a single [`NNJson`]. Blank lines are
+    /// skipped; any parse or conversion failure reports the 1-based line number.
+    pub fn from_ndjson<R: BufRead>(r: R) -> std::result::Result<Self, LoadError> {
+        let mut network = Self::new();
+        for (index, line) in r.lines().enumerate() {
+            let line_no = index + 1;
+            let line = line.map_err(|source| LoadError::Io { line: line_no, source })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let layer_json: LayerJson = serde_json::from_str(&line)
+                .map_err(|source| LoadError::Parse { line: line_no, source })?;
+            let layer = Box::<dyn Layer>::try_from(layer_json)
+                .map_err(|source| LoadError::Convert { line: line_no, source })?;
+            network.add_layer(layer);
+        }
+        Ok(network)
+    }
+
+    pub fn apply(
+        &self,
+        input: &ArrayViewD<f32>,
+    ) -> std::result::Result<ArrayD<f32>, ShapeError> {
+        self.validate(input.shape())?;
+
+        let mut output = input.view().into_owned();
+        for layer in &self.layers {
+            output = layer.apply(&output.view());
+            println!("{}", layer);
+        }
+        Ok(output)
+    }
+}
+
+/// Aggregate circuit-cost summary produced by [`NeuralNetwork::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkReport {
+    pub num_layers:   usize,
+    pub num_params:   usize,
+    pub num_muls:     usize,
+    pub output_shape: Vec<usize>,
+}
+
+/// Dimensionality mismatch between two adjacent layers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShapeError {
+    pub layer_index: usize,
+    pub layer_name:  String,
+    pub expected:    Vec<usize>,
+    pub actual:      Vec<usize>,
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "shape mismatch at layer {} ({}): expected {:?}, got {:?}",
+            self.layer_index, self.layer_name, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+/// Error raised by [`NeuralNetwork::from_ndjson`], tagged with the 1-based line
+/// number that failed.
+#[derive(Debug)]
+pub enum LoadError {
+    Io {
+        line:   usize,
+        source: std::io::Error,
+    },
+    Parse {
+        line:   usize,
+        source: serde_json::Error,
+    },
+    Convert {
+        line:   usize,
+        source: ModelError,
+    },
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io { line, source } => write!(f, "line {line}: io error: {source}"),
+            Self::Parse { line, source } => write!(f, "line {line}: parse error: {source}"),
+            Self::Convert { line, source } => write!(f, "line {line}: {source}"),
         }
     }
 }
 
+impl std::error::Error for LoadError {}
+
 impl Default for NeuralNetwork {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Wire format selector for [`NeuralNetwork::save`] / [`NeuralNetwork::load`].
+///
+/// All three variants serialize the canonical [`NNJson`] serde schema, so they
+/// share one model and every tensor round-trips exactly. The binary backends
+/// replace JSON's decimal-text floats with their native numeric encodings —
+/// `Bincode` writes each `f32` as 4 raw little-endian bytes, `MessagePack`
+/// writes compact tag-prefixed floats — yielding files a fraction of the JSON
+/// size without the lossy text conversion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    MessagePack,
+    Bincode,
+}
+
+/// Error raised by [`NeuralNetwork::save`] / [`NeuralNetwork::load`].
+#[derive(Debug)]
+pub enum SerializationError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    MessagePackEncode(rmp_serde::encode::Error),
+    MessagePackDecode(rmp_serde::decode::Error),
+    Bincode(bincode::Error),
+    Model(ModelError),
+}
+
+impl Display for SerializationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::Json(e) => write!(f, "json error: {e}"),
+            Self::MessagePackEncode(e) => write!(f, "messagepack encode error: {e}"),
+            Self::MessagePackDecode(e) => write!(f, "messagepack decode error: {e}"),
+            Self::Bincode(e) => write!(f, "bincode error: {e}"),
+            Self::Model(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {}
+
+impl From<std::io::Error> for SerializationError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SerializationError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<rmp_serde::encode::Error> for SerializationError {
+    fn from(e: rmp_serde::encode::Error) -> Self {
+        Self::MessagePackEncode(e)
+    }
+}
+
+impl From<rmp_serde::decode::Error> for SerializationError {
+    fn from(e: rmp_serde::decode::Error) -> Self {
+        Self::MessagePackDecode(e)
+    }
+}
+
+impl From<bincode::Error> for SerializationError {
+    fn from(e: bincode::Error) -> Self {
+        Self::Bincode(e)
+    }
+}
+
+impl From<ModelError> for SerializationError {
+    fn from(e: ModelError) -> Self {
+        Self::Model(e)
+    }
+}
+
+impl NeuralNetwork {
+    /// Serialize the network to `w` in the chosen [`Format`].
+    pub fn save<W: Write>(&self, mut w: W, format: Format) -> std::result::Result<(), SerializationError> {
+        let model = NNJson {
+            layers: self.layers.iter().map(|l| l.to_json()).collect(),
+        };
+        match format {
+            Format::Json => serde_json::to_writer(&mut w, &model)?,
+            Format::MessagePack => rmp_serde::encode::write_named(&mut w, &model)?,
+            Format::Bincode => bincode::serialize_into(&mut w, &model)?,
+        }
+        Ok(())
+    }
+
+    /// Reconstruct a network from `r` written in the chosen [`Format`].
+    pub fn load<R: Read>(r: R, format: Format) -> std::result::Result<Self, SerializationError> {
+        let model: NNJson = match format {
+            Format::Json => serde_json::from_reader(r)?,
+            Format::MessagePack => rmp_serde::decode::from_read(r)?,
+            Format::Bincode => bincode::deserialize_from(r)?,
+        };
+        Ok(Self::try_from(model)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    fn sample_network() -> NeuralNetwork {
+        let mut nn = NeuralNetwork::new();
+        nn.add_layer(Box::new(relu::Relu::new()));
+        nn.add_layer(Box::new(maxpool::MaxPool::new(2, 2, PoolingType::Average)));
+        nn.add_layer(Box::new(fully_connected::FullyConnected::new(
+            array![[1.0_f32, 2.0], [3.0, 4.0]].into_shared(),
+            array![0.5_f32, -0.5].into_shared(),
+        )));
+        nn
+    }
+
+    fn assert_round_trips(format: Format) {
+        let mut buffer = Vec::new();
+        sample_network()
+            .save(&mut buffer, format)
+            .expect("save succeeds");
+        let loaded = NeuralNetwork::load(buffer.as_slice(), format).expect("load succeeds");
+
+        assert_eq!(
+            NNJson::from(sample_network()),
+            NNJson::from(loaded),
+            "{format:?} round-trip must preserve every layer exactly"
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        assert_round_trips(Format::Json);
+    }
+
+    #[test]
+    fn messagepack_round_trip() {
+        assert_round_trips(Format::MessagePack);
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        assert_round_trips(Format::Bincode);
+    }
+}
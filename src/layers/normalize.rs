@@ -0,0 +1,59 @@
+use ndarray::{ArrayD, ArrayViewD};
+
+use super::{Layer, LayerJson};
+
+/// Scales the input to unit L2 norm.
+pub struct Normalize;
+
+impl Normalize {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layer for Normalize {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        let norm = input.mapv(|x| x * x).sum().sqrt();
+        if norm == 0.0 {
+            input.to_owned()
+        } else {
+            input.mapv(|x| x / norm)
+        }
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        "Normalize"
+    }
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn num_muls(&self) -> usize {
+        0
+    }
+
+    fn num_muls_with_shape(&self, input_shape: &[usize]) -> usize {
+        // One multiply-by-reciprocal per element plus the squared sum.
+        2 * input_shape.iter().product::<usize>()
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::Normalize
+    }
+}
@@ -0,0 +1,50 @@
+use ndarray::{ArrayD, ArrayViewD};
+
+use super::{Layer, LayerJson};
+
+/// Elementwise rectified linear unit, `max(0, x)`.
+pub struct Relu;
+
+impl Relu {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Relu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layer for Relu {
+    fn apply(&self, input: &ArrayViewD<f32>) -> ArrayD<f32> {
+        input.mapv(|x| x.max(0.0))
+    }
+
+    fn input_shape(&self) -> Vec<usize> {
+        // Elementwise: shape-agnostic, threaded in by `NeuralNetwork::validate`.
+        vec![]
+    }
+
+    fn name(&self) -> &str {
+        "Relu"
+    }
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn num_muls(&self) -> usize {
+        0
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn to_json(&self) -> LayerJson {
+        LayerJson::Relu
+    }
+}